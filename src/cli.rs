@@ -0,0 +1,137 @@
+use std::path::PathBuf;
+
+use colored::*;
+use pico_args::Arguments;
+
+use crate::days::registered_days;
+use crate::utils::aoc::{print_header, RunnableDay};
+use crate::utils::download::download_input;
+use crate::utils::scaffold::scaffold_day;
+
+const HELP: &str = "\
+aoc2023
+
+USAGE:
+  aoc2023 <SUBCOMMAND>
+
+SUBCOMMANDS:
+  solve <DAY>       Run the solution for a single day
+  all               Run every registered day
+  time [DAY]        Benchmark a single day, or every registered day if omitted
+  verify <DAY>      Check a day's solution against its example inputs
+  scaffold <DAY>    Generate a new day module from the template
+  download <DAY>    Download the puzzle input for a day
+";
+
+enum Subcommand {
+    Solve(u32),
+    All,
+    Time(Option<u32>),
+    Verify(u32),
+    Scaffold(u32),
+    Download(u32),
+}
+
+/// Parses `std::env::args` and dispatches to the matching subcommand.
+pub fn run() -> Result<(), String> {
+    let mut args = Arguments::from_env();
+
+    if args.contains(["-h", "--help"]) {
+        print!("{}", HELP);
+        return Ok(());
+    }
+
+    let subcommand = parse_subcommand(&mut args)?;
+
+    dispatch(subcommand)
+}
+
+fn parse_subcommand(args: &mut Arguments) -> Result<Subcommand, String> {
+    let subcommand = args
+        .subcommand()
+        .map_err(|why| why.to_string())?
+        .ok_or("Expected a subcommand, see --help for usage.")?;
+
+    match subcommand.as_str() {
+        "solve" => Ok(Subcommand::Solve(parse_day(args)?)),
+        "all" => Ok(Subcommand::All),
+        "time" => Ok(Subcommand::Time(parse_optional_day(args)?)),
+        "verify" => Ok(Subcommand::Verify(parse_day(args)?)),
+        "scaffold" => Ok(Subcommand::Scaffold(parse_day(args)?)),
+        "download" => Ok(Subcommand::Download(parse_day(args)?)),
+        other => Err(format!("Unknown subcommand: {}", other)),
+    }
+}
+
+fn parse_day(args: &mut Arguments) -> Result<u32, String> {
+    args.free_from_str().map_err(|why| why.to_string())
+}
+
+fn parse_optional_day(args: &mut Arguments) -> Result<Option<u32>, String> {
+    args.opt_free_from_str().map_err(|why| why.to_string())
+}
+
+fn dispatch(subcommand: Subcommand) -> Result<(), String> {
+    match subcommand {
+        Subcommand::Solve(day) => run_day("execute", day, |solution| solution.execute()),
+        Subcommand::All => {
+            print_header().map_err(|why| why.to_string())?;
+
+            for solution in registered_days() {
+                solution.execute();
+            }
+
+            Ok(())
+        }
+        Subcommand::Time(Some(day)) => {
+            print_header().map_err(|why| why.to_string())?;
+
+            run_day("benchmark", day, |solution| solution.time())
+        }
+        Subcommand::Time(None) => {
+            print_header().map_err(|why| why.to_string())?;
+
+            for solution in registered_days() {
+                solution.time();
+            }
+
+            Ok(())
+        }
+        Subcommand::Verify(day) => run_day("verify", day, |solution| solution.verify_examples()),
+        Subcommand::Scaffold(day) => {
+            scaffold_day(day).map_err(|why| why.to_string())?;
+
+            println!("{} day {} scaffolded.", "✅".green(), day);
+
+            Ok(())
+        }
+        Subcommand::Download(day) => {
+            let destination = PathBuf::from("input").join(format!("{}.txt", day));
+
+            download_input(day, &destination).map_err(|why| why.to_string())?;
+
+            println!("{} input for day {} downloaded.", "✅".green(), day);
+
+            Ok(())
+        }
+    }
+}
+
+/// Looks up `day` in the [registered days](crate::days::registered_days) and
+/// applies `action` to it, or reports that the day hasn't been registered.
+///
+/// `action_name` (e.g. `"execute"`, `"benchmark"`, `"verify"`) describes what
+/// `action` does, so a failure is reported as what actually went wrong
+/// instead of a single generic message shared across every subcommand.
+fn run_day(
+    action_name: &str,
+    day: u32,
+    action: impl Fn(&dyn RunnableDay) -> Option<()>,
+) -> Result<(), String> {
+    let solution = registered_days()
+        .into_iter()
+        .find(|solution| solution.day() == day)
+        .ok_or_else(|| format!("Day {} is not registered.", day))?;
+
+    action(solution.as_ref()).ok_or_else(|| format!("Day {} failed to {}.", day, action_name))
+}
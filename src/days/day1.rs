@@ -1,12 +1,16 @@
+use anyhow::{anyhow, Context};
+
 use crate::utils::aoc::AocSolution;
 
 pub struct AocDay;
 
 /// Solves the AoC 2023 Day 1 challenge, see [here](https://adventofcode.com/2023/day/1).
-impl AocSolution<1> for AocDay {
+impl AocSolution for AocDay {
+    const DAY: u32 = 1;
+
     type ResponseType = u32;
 
-    fn solve_first(&self, input: &str) -> Self::ResponseType {
+    fn solve_first(&self, input: &str) -> anyhow::Result<Self::ResponseType> {
         input
             .lines()
             .map(|line| {
@@ -15,12 +19,14 @@ impl AocSolution<1> for AocDay {
 
                 let number = format!("{}{}", first, last);
 
-                number.parse::<u32>().unwrap()
+                number
+                    .parse::<u32>()
+                    .with_context(|| format!("couldn't parse calibration value from {:?}", line))
             })
             .sum()
     }
 
-    fn solve_second(&self, input: &str) -> Self::ResponseType {
+    fn solve_second(&self, input: &str) -> anyhow::Result<Self::ResponseType> {
         const NUM_MATCHES: [&str; 19] = [
             "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", //
             "0", "1", "2", "3", "4", "5", "6", "7", "8", "9",
@@ -37,40 +43,62 @@ impl AocSolution<1> for AocDay {
                 let (_, first) = matches
                     .iter()
                     .min_by(|(lhs, _), (rhs, _)| lhs.cmp(rhs))
-                    .unwrap();
+                    .ok_or_else(|| anyhow!("no digit found on line {:?}", line))?;
                 let (_, last) = matches
                     .iter()
                     .max_by(|(lhs, _), (rhs, _)| lhs.cmp(rhs))
-                    .unwrap();
+                    .ok_or_else(|| anyhow!("no digit found on line {:?}", line))?;
 
                 let number = format!(
                     "{}{}",
-                    AocDay::match_number(first),
-                    AocDay::match_number(last)
+                    AocDay::match_number(first)?,
+                    AocDay::match_number(last)?
                 );
 
-                number.parse::<u32>().unwrap()
+                number
+                    .parse::<u32>()
+                    .with_context(|| format!("couldn't parse calibration value from {:?}", line))
             })
             .sum()
     }
 }
 
 impl AocDay {
-    fn match_number(number: &str) -> u32 {
+    fn match_number(number: &str) -> anyhow::Result<u32> {
         match number.parse() {
-            Ok(number) => number,
-            Err(why) => match number {
-                "one" => 1,
-                "two" => 2,
-                "three" => 3,
-                "four" => 4,
-                "five" => 5,
-                "six" => 6,
-                "seven" => 7,
-                "eight" => 8,
-                "nine" => 9,
-                _ => panic!("Couldn't match number: {}", why),
+            Ok(number) => Ok(number),
+            Err(_) => match number {
+                "one" => Ok(1),
+                "two" => Ok(2),
+                "three" => Ok(3),
+                "four" => Ok(4),
+                "five" => Ok(5),
+                "six" => Ok(6),
+                "seven" => Ok(7),
+                "eight" => Ok(8),
+                "nine" => Ok(9),
+                _ => Err(anyhow!("couldn't match number: {:?}", number)),
             },
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn run_reports_part_two_against_its_example() {
+        let input = "two1nine\n\
+                     eightwothree\n\
+                     abcone2threexyz\n\
+                     xtwone3four\n\
+                     4nineeightseven2\n\
+                     zoneight234\n\
+                     7pqrstsixteen";
+
+        let results = AocDay.run(input);
+
+        assert_eq!(*results[1].output().as_ref().unwrap(), 281);
+    }
+}
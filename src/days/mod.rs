@@ -0,0 +1,13 @@
+use crate::utils::aoc::RunnableDay;
+
+pub mod day1;
+
+/// Returns every day that has been registered so far, in ascending order.
+///
+/// Adding a new day to the harness takes two lines here: implement
+/// [`AocSolution`](crate::utils::aoc::AocSolution) for the day in its own
+/// module, declare that module above, then box an instance of it into this
+/// list.
+pub fn registered_days() -> Vec<Box<dyn RunnableDay>> {
+    vec![Box::new(day1::AocDay)]
+}
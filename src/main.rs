@@ -0,0 +1,12 @@
+use colored::*;
+
+mod cli;
+mod days;
+mod utils;
+
+fn main() {
+    if let Err(why) = cli::run() {
+        eprintln!("{} {}", "Error:".red(), why);
+        std::process::exit(1);
+    }
+}
@@ -2,39 +2,134 @@ use std::fmt::Display;
 use std::fs;
 use std::fs::File;
 use std::io::{BufWriter, Write};
+use std::path::PathBuf;
 use std::time::{Duration, Instant};
 
 use colored::*;
 
+/// Returns the directory example inputs and their expected answers are
+/// stored under.
+fn examples_dir() -> PathBuf {
+    let mut dir = std::env::current_dir().expect("Failed to access current working directory.");
+    dir.push("examples");
+
+    dir
+}
+
 /// A struct for the result of a solution part with its result and elapsed
 /// running time of the solving method.
 pub struct AocResult<ResponseType: Display> {
-    output: ResponseType,
+    output: anyhow::Result<ResponseType>,
     elapsed: Duration,
 }
 
+impl<ResponseType: Display> AocResult<ResponseType> {
+    pub fn output(&self) -> &anyhow::Result<ResponseType> {
+        &self.output
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+}
+
+/// How long [`AocSolution::bench_execute`] keeps sampling a solving method,
+/// and the upper bound on how many samples it collects in that time.
+const BENCH_TIME_BUDGET: Duration = Duration::from_secs(1);
+const BENCH_MAX_ITERATIONS: usize = 1_000;
+
+/// Summary statistics over the elapsed times of a [`AocBench`] run.
+pub struct BenchStats {
+    pub iterations: usize,
+    pub min: Duration,
+    pub max: Duration,
+    pub mean: Duration,
+    pub median: Duration,
+    pub stddev: Duration,
+}
+
+impl BenchStats {
+    fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+
+        let iterations = samples.len();
+        let min = samples[0];
+        let max = samples[iterations - 1];
+        let mean = samples.iter().sum::<Duration>() / iterations as u32;
+        let median = samples[iterations / 2];
+
+        let variance = samples
+            .iter()
+            .map(|sample| {
+                let diff = sample.as_secs_f64() - mean.as_secs_f64();
+                diff * diff
+            })
+            .sum::<f64>()
+            / iterations as f64;
+        let stddev = Duration::from_secs_f64(variance.sqrt());
+
+        BenchStats {
+            iterations,
+            min,
+            max,
+            mean,
+            median,
+            stddev,
+        }
+    }
+}
+
+/// A struct for the result of a solution part benchmarked over many runs,
+/// carrying the output of the first (warm-up) run alongside [`BenchStats`]
+/// over the repeated timed runs.
+pub struct AocBench<ResponseType: Display> {
+    output: anyhow::Result<ResponseType>,
+    stats: BenchStats,
+}
+
+impl<ResponseType: Display> AocBench<ResponseType> {
+    pub fn output(&self) -> &anyhow::Result<ResponseType> {
+        &self.output
+    }
+
+    pub fn stats(&self) -> &BenchStats {
+        &self.stats
+    }
+}
+
 /// A trait for the solution of an Advent of Code day.
 ///
 /// Implementors of the `AocSolution` trait should implement the two parts of
 /// the day in the methods `solve_first` and `solve_second`. Both parts must
 /// have the same type. In the case they should differ, choose a common
 /// denominator between those, usually it will be [`String`].
-pub trait AocSolution<const DAY: u32> {
+///
+/// Both parts return an [`anyhow::Result`] so a malformed input line can be
+/// reported with context instead of aborting the whole run.
+pub trait AocSolution {
+    /// The day number this solution belongs to, without padded zeros.
+    const DAY: u32;
+
     type ResponseType: Display;
 
-    fn solve_first(&self, input: &str) -> Self::ResponseType;
-    fn solve_second(&self, input: &str) -> Self::ResponseType;
+    fn solve_first(&self, input: &str) -> anyhow::Result<Self::ResponseType>;
+    fn solve_second(&self, input: &str) -> anyhow::Result<Self::ResponseType>;
 
     /// Read the input file that is located in `input/<DAY>.txt`, where `DAY`
     /// is the current day number without padded zeros.
     ///
-    /// This function assumes that the input file already exists and does not
-    /// create any when it doesn't.
+    /// If the file doesn't exist yet, it is fetched from adventofcode.com
+    /// first, see [`download_input`](crate::utils::download::download_input).
     fn read_input_file(&self) -> std::io::Result<String> {
         let mut input_file =
             std::env::current_dir().expect("Failed to access current working directory.");
         input_file.push("input");
-        input_file.push(format!("{}.txt", DAY));
+        input_file.push(format!("{}.txt", Self::DAY));
+
+        if !input_file.exists() {
+            crate::utils::download::download_input(Self::DAY, &input_file)
+                .map_err(std::io::Error::other)?;
+        }
 
         fs::read_to_string(input_file)
     }
@@ -50,7 +145,7 @@ pub trait AocSolution<const DAY: u32> {
         let mut output_file =
             std::env::current_dir().expect("Failed to access current working directory.");
         output_file.push("output");
-        output_file.push(format!("{}.txt", DAY));
+        output_file.push(format!("{}.txt", Self::DAY));
 
         let output = File::options()
             .create(true)
@@ -65,10 +160,15 @@ pub trait AocSolution<const DAY: u32> {
                 &mut writer,
                 "Part {} (time: {} s)",
                 n + 1,
-                solution.elapsed.as_secs_f64()
+                solution.elapsed().as_secs_f64()
             )?;
             writeln!(&mut writer, "====================")?;
-            writeln!(&mut writer, "{}", solution.output)?;
+
+            match solution.output() {
+                Ok(output) => writeln!(&mut writer, "{}", output)?,
+                Err(why) => writeln!(&mut writer, "Failed: {:#}", why)?,
+            }
+
             writeln!(&mut writer, "====================\n")?;
         }
 
@@ -79,7 +179,7 @@ pub trait AocSolution<const DAY: u32> {
     /// [`AocResult`].
     fn timed_execute(
         &self,
-        solve_func: fn(&Self, &str) -> Self::ResponseType,
+        solve_func: fn(&Self, &str) -> anyhow::Result<Self::ResponseType>,
         input: &str,
     ) -> AocResult<Self::ResponseType> {
         let start = Instant::now();
@@ -89,19 +189,237 @@ pub trait AocSolution<const DAY: u32> {
         AocResult { output, elapsed }
     }
 
+    /// Runs the given method repeatedly and returns the output of the first
+    /// (warm-up) run alongside timing statistics over the repeated runs.
+    ///
+    /// Sampling stops once [`BENCH_TIME_BUDGET`] has elapsed or
+    /// [`BENCH_MAX_ITERATIONS`] samples have been collected, whichever comes
+    /// first; at least one sample is always taken.
+    fn bench_execute(
+        &self,
+        solve_func: fn(&Self, &str) -> anyhow::Result<Self::ResponseType>,
+        input: &str,
+    ) -> AocBench<Self::ResponseType> {
+        let output = solve_func(self, input);
+
+        let mut samples = Vec::new();
+        let budget_start = Instant::now();
+
+        while samples.len() < BENCH_MAX_ITERATIONS
+            && (samples.is_empty() || budget_start.elapsed() < BENCH_TIME_BUDGET)
+        {
+            let start = Instant::now();
+            let _ = solve_func(self, input);
+            samples.push(start.elapsed());
+        }
+
+        AocBench {
+            output,
+            stats: BenchStats::from_samples(samples),
+        }
+    }
+
+    /// Benchmarks the implemented solutions and reports min/mean/median/
+    /// stddev for each part instead of a single run's timing.
+    ///
+    /// Like [`execute`](Self::execute), this reads the input file and reports
+    /// through the console, but it never writes an output file since the
+    /// repeated runs are for comparing implementations, not submitting.
+    fn time(&self) -> Option<()> {
+        print!("Benchmarking solution for day {}... ", Self::DAY);
+
+        let input = match self.read_input_file() {
+            Ok(input) => input,
+            Err(why) => {
+                println!(
+                    "{} {} {}",
+                    "❌ Failed.".red(),
+                    "Could not read input file:".bright_black(),
+                    why.to_string().bright_black()
+                );
+
+                return None;
+            }
+        };
+
+        println!("{}", "✅ Passed.".green());
+
+        let benches = [
+            self.bench_execute(Self::solve_first, &input),
+            self.bench_execute(Self::solve_second, &input),
+        ];
+
+        for (n, bench) in benches.iter().enumerate() {
+            println!("Part {}:", n + 1);
+
+            match bench.output() {
+                Ok(output) => {
+                    println!("{}", "====== Output ======".bright_black());
+                    println!("{}", output);
+                    println!("{}", "====================".bright_black());
+                }
+                Err(why) => {
+                    println!(
+                        "{} {}",
+                        "❌ Failed.".red(),
+                        format!("{:#}", why).bright_black()
+                    );
+                }
+            }
+
+            let stats = bench.stats();
+
+            println!(
+                "  min {:.6} s · mean {:.6} s · median {:.6} s · max {:.6} s · stddev {:.6} s ({} iterations)\n",
+                stats.min.as_secs_f64(),
+                stats.mean.as_secs_f64(),
+                stats.median.as_secs_f64(),
+                stats.max.as_secs_f64(),
+                stats.stddev.as_secs_f64(),
+                stats.iterations,
+            );
+        }
+
+        Some(())
+    }
+
+    /// Runs both parts against the example input(s) under `examples/<DAY>.txt`
+    /// and checks the output against the expected answers recorded in
+    /// `examples/<DAY>.expected`, reporting pass/fail per part.
+    ///
+    /// Part two uses `examples/<DAY>-2.txt` instead, if that file exists,
+    /// since some days give part two a different sample than part one.
+    /// `examples/<DAY>.expected` holds the expected answers as two lines,
+    /// part one then part two.
+    fn verify_examples(&self) -> Option<()> {
+        println!("Verifying examples for day {}...", Self::DAY);
+
+        let expected = match self.read_expected_answers() {
+            Ok(expected) => expected,
+            Err(why) => {
+                println!(
+                    "{} {} {}",
+                    "❌ Failed.".red(),
+                    "Could not read expected answers:".bright_black(),
+                    why.to_string().bright_black()
+                );
+
+                return None;
+            }
+        };
+
+        let first_passed = self.verify_example(1, Self::solve_first, &expected[0]);
+        let second_passed = self.verify_example(2, Self::solve_second, &expected[1]);
+
+        (first_passed && second_passed).then_some(())
+    }
+
+    /// Reads `examples/<DAY>.expected` and returns its first two lines, the
+    /// expected answers for part one and part two respectively.
+    fn read_expected_answers(&self) -> std::io::Result<[String; 2]> {
+        let expected_file = examples_dir().join(format!("{}.expected", Self::DAY));
+        let contents = fs::read_to_string(expected_file)?;
+        let mut lines = contents.lines();
+
+        let first = lines.next().unwrap_or_default().to_string();
+        let second = lines.next().unwrap_or_default().to_string();
+
+        Ok([first, second])
+    }
+
+    /// Reads the example input for `part` (1 or 2), preferring
+    /// `examples/<DAY>-<part>.txt` over `examples/<DAY>.txt` when it exists.
+    fn read_example_file(&self, part: u32) -> std::io::Result<String> {
+        let part_specific = examples_dir().join(format!("{}-{}.txt", Self::DAY, part));
+
+        let example_file = if part_specific.exists() {
+            part_specific
+        } else {
+            examples_dir().join(format!("{}.txt", Self::DAY))
+        };
+
+        fs::read_to_string(example_file)
+    }
+
+    /// Verifies `part` against `expected` and reports pass/fail, returning
+    /// whether it passed so [`verify_examples`](Self::verify_examples) can
+    /// use the overall result as a pass/fail gate.
+    fn verify_example(
+        &self,
+        part: u32,
+        solve_func: fn(&Self, &str) -> anyhow::Result<Self::ResponseType>,
+        expected: &str,
+    ) -> bool {
+        let input = match self.read_example_file(part) {
+            Ok(input) => input,
+            Err(why) => {
+                println!(
+                    "Part {} {} {} {}",
+                    part,
+                    "❌ Failed.".red(),
+                    "Could not read example file:".bright_black(),
+                    why.to_string().bright_black()
+                );
+
+                return false;
+            }
+        };
+
+        match solve_func(self, &input) {
+            Ok(output) if output.to_string() == expected => {
+                println!("Part {} {}", part, "✅ Passed.".green());
+
+                true
+            }
+            Ok(output) => {
+                println!(
+                    "Part {} {} {}",
+                    part,
+                    "❌ Failed.".red(),
+                    format!("expected {:?}, got {:?}", expected, output.to_string())
+                        .bright_black()
+                );
+
+                false
+            }
+            Err(why) => {
+                println!(
+                    "Part {} {} {}",
+                    part,
+                    "❌ Failed.".red(),
+                    format!("{:#}", why).bright_black()
+                );
+
+                false
+            }
+        }
+    }
+
+    /// Runs both solving methods against `input` and returns their results,
+    /// with no printing or filesystem access.
+    ///
+    /// This is the pure entry point external criterion benches and `#[test]`
+    /// functions should use to drive a day with in-memory input; [`execute`]
+    /// is a thin wrapper around it that adds file I/O and console reporting.
+    ///
+    /// [`execute`]: Self::execute
+    fn run(&self, input: &str) -> [AocResult<Self::ResponseType>; 2] {
+        [
+            self.timed_execute(Self::solve_first, input),
+            self.timed_execute(Self::solve_second, input),
+        ]
+    }
+
     /// Setups and executes the implemented solutions.
     ///
     /// This method will read the input file for the given day, run both
     /// solving methods and write the output to an output file as well as
     /// the console.
     fn execute(&self) -> Option<()> {
-        print!("Executing solution for day {}... ", DAY);
+        print!("Executing solution for day {}... ", Self::DAY);
 
         let solutions = match self.read_input_file() {
-            Ok(input) => vec![
-                self.timed_execute(Self::solve_first, &input),
-                self.timed_execute(Self::solve_second, &input),
-            ],
+            Ok(input) => Vec::from(self.run(&input)),
             Err(why) => {
                 println!(
                     "{} {} {}",
@@ -120,11 +438,23 @@ pub trait AocSolution<const DAY: u32> {
             println!(
                 "Part {} ran for {} s.",
                 n + 1,
-                solution.elapsed.as_secs_f64()
+                solution.elapsed().as_secs_f64()
             );
-            println!("{}", "====== Output ======".bright_black());
-            println!("{}", solution.output);
-            println!("{}", "====================\n".bright_black());
+
+            match solution.output() {
+                Ok(output) => {
+                    println!("{}", "====== Output ======".bright_black());
+                    println!("{}", output);
+                    println!("{}", "====================\n".bright_black());
+                }
+                Err(why) => {
+                    println!(
+                        "{} {}",
+                        "❌ Failed.".red(),
+                        format!("{:#}", why).bright_black()
+                    );
+                }
+            }
         }
 
         if let Err(why) = self.write_output_file(&solutions) {
@@ -140,6 +470,40 @@ pub trait AocSolution<const DAY: u32> {
     }
 }
 
+/// An object-safe view of an [`AocSolution`], so the CLI dispatcher can hold
+/// solutions for different days (and different `ResponseType`s) behind a
+/// single trait object instead of `DAY` being baked into the collection's
+/// type.
+pub trait RunnableDay {
+    /// The day number this solution was registered under.
+    fn day(&self) -> u32;
+
+    fn execute(&self) -> Option<()>;
+    fn time(&self) -> Option<()>;
+    fn verify_examples(&self) -> Option<()>;
+}
+
+impl<T> RunnableDay for T
+where
+    T: AocSolution,
+{
+    fn day(&self) -> u32 {
+        T::DAY
+    }
+
+    fn execute(&self) -> Option<()> {
+        AocSolution::execute(self)
+    }
+
+    fn time(&self) -> Option<()> {
+        AocSolution::time(self)
+    }
+
+    fn verify_examples(&self) -> Option<()> {
+        AocSolution::verify_examples(self)
+    }
+}
+
 pub fn print_header() -> std::io::Result<()> {
     let stdout = std::io::stdout();
     let mut lock = stdout.lock();
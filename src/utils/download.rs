@@ -0,0 +1,88 @@
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use reqwest::blocking::Client;
+use reqwest::header::{COOKIE, USER_AGENT};
+
+const USER_AGENT_STRING: &str =
+    "github.com/dakralex/AoC-2023 by dakralex (input downloader, see repo for contact)";
+
+#[derive(Debug)]
+pub enum DownloadError {
+    MissingSession,
+    Request(reqwest::Error),
+    Status(reqwest::StatusCode),
+    Io(std::io::Error),
+}
+
+impl fmt::Display for DownloadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DownloadError::MissingSession => write!(
+                f,
+                "no AoC session token found (set AOC_SESSION or add it to a .env file)"
+            ),
+            DownloadError::Request(why) => write!(f, "request failed: {}", why),
+            DownloadError::Status(status) => write!(f, "request failed with status {}", status),
+            DownloadError::Io(why) => write!(f, "could not write input file: {}", why),
+        }
+    }
+}
+
+impl std::error::Error for DownloadError {}
+
+impl From<reqwest::Error> for DownloadError {
+    fn from(why: reqwest::Error) -> Self {
+        DownloadError::Request(why)
+    }
+}
+
+impl From<std::io::Error> for DownloadError {
+    fn from(why: std::io::Error) -> Self {
+        DownloadError::Io(why)
+    }
+}
+
+fn read_session_token() -> Result<String, DownloadError> {
+    dotenvy::dotenv().ok();
+
+    std::env::var("AOC_SESSION").map_err(|_| DownloadError::MissingSession)
+}
+
+/// Downloads the puzzle input for `day` to `destination`, unless it already
+/// exists.
+///
+/// The request is authenticated with a `session` cookie read from the
+/// `AOC_SESSION` environment variable or a `.env` file, and is sent with a
+/// descriptive user agent as requested by AoC's [automation
+/// etiquette](https://www.reddit.com/r/adventofcode/wiki/faqs/automation).
+pub fn download_input(day: u32, destination: &Path) -> Result<(), DownloadError> {
+    if destination.exists() {
+        return Ok(());
+    }
+
+    let session = read_session_token()?;
+    let url = format!("https://adventofcode.com/2023/day/{}/input", day);
+
+    let client = Client::new();
+    let response = client
+        .get(&url)
+        .header(USER_AGENT, USER_AGENT_STRING)
+        .header(COOKIE, format!("session={}", session))
+        .send()?;
+
+    if !response.status().is_success() {
+        return Err(DownloadError::Status(response.status()));
+    }
+
+    let body = response.text()?;
+
+    if let Some(parent) = destination.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    fs::write(destination, body)?;
+
+    Ok(())
+}
@@ -0,0 +1,3 @@
+pub mod aoc;
+pub mod download;
+pub mod scaffold;
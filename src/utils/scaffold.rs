@@ -0,0 +1,45 @@
+use std::fs;
+use std::path::PathBuf;
+
+const DAY_TEMPLATE: &str = r#"use crate::utils::aoc::AocSolution;
+
+pub struct AocDay;
+
+/// Solves the AoC 2023 Day {day} challenge, see [here](https://adventofcode.com/2023/day/{day}).
+impl AocSolution for AocDay {
+    const DAY: u32 = {day};
+
+    type ResponseType = u32;
+
+    fn solve_first(&self, input: &str) -> anyhow::Result<Self::ResponseType> {
+        todo!()
+    }
+
+    fn solve_second(&self, input: &str) -> anyhow::Result<Self::ResponseType> {
+        todo!()
+    }
+}
+"#;
+
+/// Generates a new day module at `src/days/day<DAY>.rs` from [`DAY_TEMPLATE`].
+///
+/// This only creates the module file; it does not wire the day into
+/// [`registered_days`](crate::days::registered_days) or declare it in
+/// `src/days/mod.rs`, since those lines should stay a deliberate, reviewable
+/// edit.
+pub fn scaffold_day(day: u32) -> std::io::Result<()> {
+    let mut day_file = PathBuf::from("src");
+    day_file.push("days");
+    day_file.push(format!("day{}.rs", day));
+
+    if day_file.exists() {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::AlreadyExists,
+            format!("{} already exists", day_file.display()),
+        ));
+    }
+
+    let contents = DAY_TEMPLATE.replace("{day}", &day.to_string());
+
+    fs::write(&day_file, contents)
+}